@@ -0,0 +1,41 @@
+//! Compares the base58 encoding used for instruction data and signatures
+//! against encoding into a reused buffer, at the sizes the service produces.
+//!
+//! Run with `cargo bench --bench base58`.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+/// Instruction data for transfer/mint_to (9 bytes), transfer_checked_with_fee
+/// (18), a pubkey (32), and a signature or keypair secret (64).
+const SIZES: &[usize] = &[9, 18, 32, 64];
+
+fn encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("base58");
+    for &size in SIZES {
+        let bytes: Vec<u8> = (0..size).map(|i| (i * 37 + 11) as u8).collect();
+
+        group.bench_with_input(BenchmarkId::new("into_string", size), &bytes, |b, bytes| {
+            b.iter(|| bs58::encode(black_box(bytes)).into_string())
+        });
+
+        let mut buffer = String::with_capacity(size * 2);
+        group.bench_with_input(BenchmarkId::new("onto_reused", size), &bytes, |b, bytes| {
+            b.iter(|| {
+                buffer.clear();
+                bs58::encode(black_box(bytes)).onto(&mut buffer).unwrap();
+                black_box(&buffer);
+            })
+        });
+
+        assert_eq!(bs58::encode(&bytes).into_string(), {
+            let mut out = String::new();
+            bs58::encode(&bytes).onto(&mut out).unwrap();
+            out
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, encode);
+criterion_main!(benches);